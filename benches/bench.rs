@@ -31,6 +31,7 @@ mod event;
 mod files;
 mod http;
 mod lua;
+mod prometheus;
 
 criterion_group!(
     benches,
@@ -53,6 +54,7 @@ criterion_main!(
     files::files,
     lua::lua,
     event::event,
+    prometheus::prometheus,
 );
 
 fn benchmark_simple_pipe(c: &mut Criterion) {
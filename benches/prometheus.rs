@@ -0,0 +1,85 @@
+use criterion::{criterion_group, Benchmark, Criterion, Throughput};
+use std::{collections::BTreeMap, net::SocketAddr, sync::Arc};
+use vector::{
+    config,
+    event::metric::{Metric, MetricKind, MetricValue},
+    sinks::prometheus::{PrometheusSinkConfig, ScrapeCallback},
+    sources,
+    test_util::{next_addr, runtime, start_topology, wait_for_tcp},
+};
+
+fn gauges(num_series: usize) -> Vec<Metric> {
+    (0..num_series)
+        .map(|i| {
+            let tags: BTreeMap<_, _> = vec![
+                ("host".to_owned(), format!("host-{}", i % 100)),
+                ("pod".to_owned(), format!("pod-{}", i)),
+                ("region".to_owned(), "us-east-1".to_owned()),
+            ]
+            .into_iter()
+            .collect();
+            Metric {
+                name: format!("gauge_{}", i % 50),
+                timestamp: None,
+                tags: Some(tags),
+                kind: MetricKind::Absolute,
+                value: MetricValue::Gauge { value: i as f64 },
+            }
+        })
+        .collect()
+}
+
+async fn scrape(address: SocketAddr) -> usize {
+    let response = hyper::Client::new()
+        .get(format!("http://{}/metrics", address).parse().unwrap())
+        .await
+        .unwrap();
+    hyper::body::to_bytes(response.into_body())
+        .await
+        .unwrap()
+        .len()
+}
+
+fn benchmark_absolute_gauges(c: &mut Criterion) {
+    let num_series: usize = 10_000;
+
+    let in_addr = next_addr();
+    let out_addr = next_addr();
+
+    // the metrics are handed over on every scrape, so the benchmark measures
+    // encoding rather than ingestion
+    let metrics = Arc::new(gauges(num_series));
+    let callback = ScrapeCallback::new(move || metrics.as_ref().clone());
+
+    let mut config = config::Config::builder();
+    config.add_source(
+        "in",
+        sources::socket::SocketConfig::make_tcp_config(in_addr),
+    );
+    config.add_sink(
+        "out",
+        &["in"],
+        PrometheusSinkConfig::builder()
+            .address(out_addr)
+            .scrape_callback(callback)
+            .build(),
+    );
+
+    let mut rt = runtime();
+    let _topology = rt.block_on(async move {
+        let (topology, _crash) = start_topology(config.build().unwrap(), false).await;
+        wait_for_tcp(out_addr).await;
+        topology
+    });
+
+    let bench = Benchmark::new("absolute_gauges", move |b| {
+        b.iter(|| rt.block_on(scrape(out_addr)))
+    })
+    .sample_size(20)
+    .noise_threshold(0.05)
+    .throughput(Throughput::Elements(num_series as u64));
+
+    c.bench("prometheus", bench);
+}
+
+criterion_group!(prometheus, benchmark_absolute_gauges);
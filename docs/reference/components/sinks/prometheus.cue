@@ -64,34 +64,626 @@ components: sinks: prometheus: {
 	}
 
 	configuration: {
+		absolute_merge: {
+			common:      false
+			description: "How absolute updates to the same [counter][docs.data-model.metric#counter] or [gauge][docs.data-model.metric#gauge] series are combined when several arrive between two scrapes. Each scrape starts a new window. Other metric types always keep the last update."
+			required:    false
+			warnings: []
+			type: string: {
+				default: "last"
+				enum: {
+					last: "Keep the last update."
+					sum:  "Sum the updates."
+					max:  "Keep the largest update."
+					min:  "Keep the smallest update."
+					avg:  "Average the updates."
+				}
+			}
+		}
 		address: {
-			description: "The address to expose for scraping."
+			description: "The address to expose for scraping. Like any option, it can reference environment variables, as in `0.0.0.0:${METRICS_PORT}`; loading the configuration fails with the resulting address if a referenced variable is unset or not a valid port."
 			required:    true
 			warnings: []
 			type: string: {
 				examples: ["0.0.0.0:\(_port)"]
 			}
 		}
-		buckets: {
+		admin_endpoints: {
+			common:      false
+			description: "Whether to serve `POST /-/pause` and `POST /-/resume`. While paused, incoming events are acknowledged but not stored, so scrapes keep serving the metrics as they were when ingestion was paused."
+			required:    false
+			warnings: ["The endpoints are not authenticated, so they can only be enabled along with `allowed_cidrs`, restricting the peers allowed to send requests to trusted ones."]
+			type: bool: default: false
+		}
+		aggregate_drop_labels: {
+			common:      false
+			description: "Labels removed from the exposed metrics, collapsing the series that become identical into one. [Counters][docs.data-model.metric#counter], histograms, distributions and sets are summed, gauges are combined as set by `aggregate_gauges`, and summaries can't be combined so they keep all their labels. The stored series are left untouched."
+			required:    false
+			warnings: ["Aggregating scans every exposed series on each scrape."]
+			type: array: {
+				default: []
+				items: type: string: examples: ["pod"]
+			}
+		}
+		aggregate_gauges: {
+			common:      false
+			description: "How the [gauges][docs.data-model.metric#gauge] collapsed by `aggregate_drop_labels` are combined."
+			required:    false
+			warnings: []
+			type: string: {
+				default: "sum"
+				enum: {
+					last: "Keep the last gauge."
+					sum:  "Sum the gauges."
+					max:  "Keep the largest gauge."
+					min:  "Keep the smallest gauge."
+					avg:  "Average the gauges."
+				}
+			}
+		}
+		allow_negative_summary_sum: {
+			common:      false
+			description: "Whether the `_sum` series of summaries computed from distributions can be negative, as it is when negative values are observed. When disabled, negative sums are exposed as `0` for dashboards assuming they can't be negative, while the quantiles and the `_min`, `_max` and `_avg` series are left as is."
+			required:    false
+			warnings: []
+			type: bool: default: true
+		}
+		allowed_cidrs: {
+			common:      false
+			description: "The CIDRs of the peers allowed to send requests. Requests from other peers are rejected with `403 Forbidden` before any other handling. Any peer is allowed when empty."
+			required:    false
+			warnings: []
+			type: array: {
+				default: []
+				items: type: string: examples: ["10.0.0.0/8", "192.168.0.1/32"]
+			}
+		}
+		bind_retries: {
+			common:      false
+			description: "How many more times to try binding an address that is in use, such as one not yet released by a previous Vector process, before giving up. Each attempt is logged, and the healthcheck fails if an address couldn't be bound."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: 0
+				examples: [5]
+				unit:    null
+			}
+		}
+		bind_retry_interval_secs: {
+			common:      false
+			description: "The time to wait between two attempts at binding an address."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: 1
+				unit:    "seconds"
+			}
+		}
+		blank_line_between_families: {
+			common:      false
+			description: "Whether to separate metric families with a blank line, for parsers that require it. Prometheus ignores blank lines. Can't be combined with `emit_eof`, as OpenMetrics forbids blank lines."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		bucket_label_precision: {
+			common:      false
+			description: "The number of decimals used to render histogram bucket boundaries in the `le` label. By default the shortest representation of the boundary is used."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [3]
+				unit: null
+			}
+		}
+		bucket_schemas: {
+			common:      false
+			description: "A map from unit, such as `seconds` or `bytes`, to the buckets of the histograms of the metrics of that unit, listed or generated like `buckets`. The unit of a metric is set with `unit_of`. Metrics without a unit use `buckets`."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: [{"seconds": [0.01, 0.1, 1.0, 10.0], "bytes": [1024.0, 65536.0, 1048576.0]}]
+				options: {}
+			}
+		}
+		bucket_significant_digits: {
+			common:      false
+			description: "The number of significant digits histogram bucket boundaries are rounded to when the sink is built, so float drift of computed or generated boundaries, like `0.30000000000000004`, doesn't end up in the `le` label. By default boundaries are used as configured."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [6]
+				unit: null
+			}
+		}
+		buckets: {
+			common:      false
+			description: "Default buckets to use for aggregating [distribution][docs.data-model.metric#distribution] metrics into histograms. Instead of a list, the buckets can be generated, see [Generated Buckets](#generated-buckets)."
+			required:    false
+			warnings: []
+			type: array: {
+				default: [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]
+				items: type: float: examples: [0.005, 0.01]
+			}
+		}
+		config_endpoint: {
+			common:      false
+			description: "Whether to serve `GET /-/config`, the configuration in effect as JSON, with generated buckets expanded, to confirm which buckets, quantiles and overrides apply. Credentials in the URLs it holds, such as the user info of `shutdown_push_url`, are redacted."
+			required:    false
+			warnings: ["The endpoint is not authenticated, so it can only be enabled along with `allowed_cidrs`, restricting the peers allowed to send requests to trusted ones."]
+			type: bool: default: false
+		}
+		counter_total_alias: {
+			common:      false
+			description: "Whether to also expose every counter whose name doesn't end with `_total` under an alias with that suffix, as a family of its own. Meant for migrating dashboards and alerts to the suffixed names, and to be disabled once they are, since it doubles the series of these counters."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		dedicated_server_threads: {
+			common:      false
+			description: "The number of threads of a runtime dedicated to serving requests, so rendering large scrapes doesn't compete with ingestion for the shared runtime. By default requests are served on the shared runtime."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [2]
+				unit: null
+			}
+		}
+		default_max_series_per_metric: {
+			common:      false
+			description: "The maximum number of series of the metrics not listed in `max_series_per_metric`. Unlimited when unset."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [10000]
+				unit:    null
+			}
+		}
+		default_tenant: {
+			common:      false
+			description: "The tenant of the series without the `tenant_label` label. When unset, these series keep their name."
+			required:    false
+			warnings: []
+			type: string: {
+				default: null
+				examples: ["shared"]
+			}
+		}
+		derived_labels: {
+			common:      false
+			description: "A map from label name to a template its value is rendered from, where every `{label}` is replaced with the value of that label. Derived labels are added as metrics are exposed and replace any label of the same name."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: [{"endpoint": "{method} {path}"}]
+				options: {}
+			}
+		}
+		derived_labels_missing: {
+			common:      false
+			description: "How a derived label referencing a label the metric lacks is rendered."
+			required:    false
+			warnings: []
+			type: string: {
+				default: "empty"
+				enum: {
+					empty: "Render the missing label as an empty string."
+					skip:  "Leave the derived label out."
+				}
+			}
+		}
+		drop_non_finite: {
+			common:      false
+			description: "Whether to omit [counter][docs.data-model.metric#counter] and [gauge][docs.data-model.metric#gauge] samples whose value is infinite or NaN, for backends that reject them. Otherwise they are exposed as `+Inf`, `-Inf` and `NaN`."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		drop_out_of_order: {
+			common:      false
+			description: "Whether to drop samples with a timestamp older than the newest one received for their series, so a late sample doesn't replace a newer value. Samples without a timestamp are never dropped. The newest timestamp is kept with the state of the series, and taken from its stored sample once the series is evicted past `max_tracked_series`."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		dual_emit: {
+			common:      false
+			description: "Exposes every [distribution][docs.data-model.metric#distribution] metric both as a summary and as a histogram, as two families named after the distribution with distinct suffixes. This doubles the work of encoding distributions."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: []
+				options: {
+					enabled: {
+						common:      false
+						description: "Whether to expose distributions as both families."
+						required:    false
+						warnings: []
+						type: bool: default: false
+					}
+					histogram_suffix: {
+						common:      false
+						description: "The suffix appended to the name of the histogram family."
+						required:    false
+						warnings: []
+						type: string: {
+							default: "_histogram"
+							examples: ["_hist"]
+						}
+					}
+					summary_suffix: {
+						common:      false
+						description: "The suffix appended to the name of the summary family. It must differ from `histogram_suffix`."
+						required:    false
+						warnings: []
+						type: string: {
+							default: "_summary"
+							examples: ["_quantiles"]
+						}
+					}
+				}
+			}
+		}
+		emit_ack_metrics: {
+			common:      false
+			description: "Whether to append a `<namespace>_prometheus_events_acked_total` counter of the events the sink acked, and a `<namespace>_prometheus_events_in_flight` gauge of those it received but didn't ack yet, to every scrape. They help telling whether the sink keeps up with the events buffered upstream. The metrics are not stored."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		emit_cardinality_metrics: {
+			common:      false
+			description: "Whether to append a `<namespace>_prometheus_label_cardinality` gauge per label to every scrape, counting the distinct values of the label across the exposed metrics, to catch cardinality blowups early. The gauges are generated per scrape and are not stored."
+			required:    false
+			warnings: ["Computing the gauges scans every exposed series on each scrape."]
+			type: bool: default: false
+		}
+		emit_eof: {
+			common:      false
+			description: "Whether to end every scrape with the OpenMetrics `# EOF` marker, after any generated metrics, for consumers that require it. Expositions are then served, and pushed on shutdown, as `application/openmetrics-text; version=1.0.0; charset=utf-8` rather than `text/plain; version=0.0.4`, as they may hold syntax only valid in OpenMetrics, such as exemplars."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		emit_histogram_count: {
+			common:      false
+			description: "Whether to expose the `_count` series of aggregated histograms. Disable it when upstreams don't report a reliable count."
+			required:    false
+			warnings: []
+			type: bool: default: true
+		}
+		emit_histogram_sum: {
+			common:      false
+			description: "Whether to expose the `_sum` series of aggregated histograms. Disable it when upstreams don't compute the sum, as a bogus `_sum` skews queries relying on it."
+			required:    false
+			warnings: []
+			type: bool: default: true
+		}
+		emit_kind_label: {
+			common:      false
+			description: "Whether to label every series with the kind of the updates it was received as, `vector_metric_kind=\"incremental\"` or `vector_metric_kind=\"absolute\"`. Updates of the same metric received as different kinds then form distinct series."
+			required:    false
+			warnings: ["Can double the number of exposed series."]
+			type: bool: default: false
+		}
+		emit_last_update: {
+			common:      false
+			description: "Whether to expose, along with every series, a `<name>_last_update_seconds` [gauge][docs.data-model.metric#gauge] with the same labels, of the number of seconds since the series was last updated, for staleness dashboards. The time of the last update is kept with the state of the series, so the gauge isn't exposed for series evicted past `max_tracked_series`."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		emit_original_name_label: {
+			common:      false
+			description: "Whether to label the metrics renamed by `sanitize_names` with their original name, under the `original_name_label` label. Metrics whose name is already valid aren't labeled."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		emit_scrape_errors: {
+			common:      false
+			description: "Whether to append a `<namespace>_prometheus_scrape_errors_total` counter to every scrape, counting the series left out of scrapes or dropped as they were received, by `reason`. Series that can't be encoded are counted on every scrape, with the kind of encoding error as the reason, while dropped series are counted as `unnamed_metric`, `label_limit`, `negative_counter`, `out_of_order` or `statistic_mismatch`. The counters are not stored."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		encode_parallelism: {
+			common:      false
+			description: "The number of threads encoding the [distributions][docs.data-model.metric#distribution] of a scrape, as bucketing their values is the costliest part of rendering scrapes with many of them. The series are assembled in the same order as when they are encoded by the scrape, so the exposition is the same whatever the parallelism. The threads are started along with the sink and shared by concurrent scrapes, so scrapes don't start threads of their own. When unset, or set to `1`, distributions are encoded by the scrape itself."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [4]
+				unit:    null
+			}
+		}
+		exemplar_from_label: {
+			common:      false
+			description: "A high cardinality label, such as a trace ID, removed from every series and kept as the exemplar of counters instead. The value of the last counter update carrying the label is exposed as an OpenMetrics exemplar when `emit_eof` is enabled, since parsers of the Prometheus text format reject exemplars. Series that only differ by this label are merged."
+			required:    false
+			warnings: []
+			type: string: {
+				default: null
+				examples: ["trace_id"]
+			}
+		}
+		expire_after_scrapes: {
+			common:      false
+			description: "The number of scrapes a series can be exposed by without being updated in between. Series past this count are dropped after the scrape, which suits scrape intervals that vary too much for a time-based expiry. Series whose state was evicted past `max_tracked_series` are taken as idle, and dropped after the next scrape."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [5]
+				unit:    null
+			}
+		}
+		explode_delimiter: {
+			common:      false
+			description: "The delimiter separating the values of the `explode_labels` labels."
+			required:    false
+			warnings: []
+			type: string: {
+				default: ","
+				examples: ["|"]
+			}
+		}
+		explode_labels: {
+			common:      false
+			description: "Labels packing several values separated by `explode_delimiter`, such as `services=\"a,b\"`. Each series is exposed as one series per value, with the label holding that value alone, so aggregations by the label work. Values are trimmed of surrounding whitespace. This is applied after `relabel_configs`."
+			required:    false
+			warnings: ["Series exploded into a series that is also received on its own are both exposed, with identical labels."]
+			type: array: {
+				default: []
+				items: type: string: examples: ["services"]
+			}
+		}
+		explode_values: {
+			common:      false
+			description: "How the value of a series is shared by the series it's exploded into."
+			required:    false
+			warnings: []
+			type: string: {
+				default: "replicate"
+				enum: {
+					replicate: "Each series gets the whole value."
+					divide:    "The values of counters and gauges are divided evenly among the series. Other values are replicated."
+				}
+			}
+		}
+		family_endpoint_prefix: {
+			common:      false
+			description: "A path prefix under which to serve `GET <prefix><name>`, the exposition of the single metric family of that name, namespace included, or `404 Not Found` if the listener doesn't expose it. Meant for debugging without searching through a full scrape. The prefix must start and end with `/`, and can't be under `/-/`. When unset, the endpoint isn't served."
+			required:    false
+			warnings: []
+			type: string: {
+				default: null
+				examples: ["/metrics/"]
+			}
+		}
+		flush_period_overrides: {
+			common:      false
+			description: "A map from metric name to the interval, in seconds, between resets of its [set][docs.data-model.metric#set] values, overriding `flush_period_secs`. Each interval must be at least 1 second."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: [{"active_users": 300}]
+				options: {}
+			}
+		}
+		flush_period_secs: {
+			common:      false
+			description: "Time interval between [set][docs.data-model.metric#set] values are reset."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: 60
+				unit:    "seconds"
+			}
+		}
+		gauge_window_stats: {
+			common:      false
+			description: "The names of the gauges to also expose the minimum, maximum and last of the values received between two scrapes for, as `<name>_min`, `<name>_max` and `<name>_last` gauges with the same labels. The window restarts from the last value on each scrape, so a gauge that isn't updated keeps reporting it."
+			required:    false
+			warnings: []
+			type: array: {
+				default: []
+				items: type: string: examples: ["temperature"]
+			}
+		}
+		hold_last_value_secs: {
+			common:      false
+			description: "The number of seconds a [gauge][docs.data-model.metric#gauge] keeps being exposed with its last value after its last update, even once `expire_after_scrapes` would expire it, so a brief gap of the source doesn't show up as a gap of the series. Other metrics expire as usual, as holding a counter would hide a reset of its source."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [30]
+				unit:    "seconds"
+			}
+		}
+		host_label: {
+			common:      false
+			description: "The label holding the hostname when `inject_host_label` is set."
+			required:    false
+			warnings: []
+			type: string: {
+				default: "host"
+				examples: ["instance"]
+			}
+		}
+		influx_endpoint: {
+			common:      false
+			description: "Whether to serve `GET /-/influx`, the metrics stored by the listener in the InfluxDB line protocol, for stacks that scrape line protocol. Counters and gauges are written as a `value` field and distributions as their statistic fields, with the kind of the metric in a `metric_type` tag. Relabeling and aggregation are not applied."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		info_metrics: {
+			common:      false
+			description: "Metrics carrying static information, such as build metadata, in their labels. The `info` type only exists in OpenMetrics, so each is exposed in the Prometheus text format as a [gauge][docs.data-model.metric#gauge] named `<name>_info` with a value of 1, after `self_up_metric` and before the stored metrics."
+			required:    false
+			warnings: []
+			type: array: {
+				default: []
+				items: type: object: {
+					options: {
+						labels: {
+							common:      false
+							description: "The labels of the metric."
+							required:    false
+							warnings: []
+							type: object: {
+								examples: [{"version": "1.2.3"}]
+								options: {}
+							}
+						}
+						name: {
+							description: "The name of the metric, without the `_info` suffix."
+							required:    true
+							warnings: []
+							type: string: {
+								examples: ["build"]
+							}
+						}
+					}
+				}
+			}
+		}
+		inject_host_label: {
+			common:      false
+			description: "Whether to label every series with the hostname of the node running Vector, under the `host_label` label. The hostname is resolved once when the sink is built; if that fails, a warning is logged and series aren't labeled with it. Series that already have the label keep their value."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		label_hash: {
+			common:      false
+			description: "A map from label name to a number of buckets. The values of these labels are replaced with a stable hash of the value modulo the bucket count, bounding the number of series while preserving some grouping. Series whose values land in the same bucket are merged when they are received."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: [{"user_id": 64}]
+				options: {}
+			}
+		}
+		label_limit_action: {
+			common:      false
+			description: "What happens to series received with more labels than `max_labels_per_series`."
+			required:    false
+			warnings: []
+			type: string: {
+				default: "drop"
+				enum: {
+					drop:     "Drop the series."
+					truncate: "Keep the `max_labels_per_series` labels that sort first by name, which can merge series that only differ by the removed labels."
+				}
+			}
+		}
+		listeners: {
+			common:      false
+			description: "Additional addresses to expose metrics on, each serving only the metrics whose name starts with one of its prefixes. Metrics claimed by a listener are no longer exposed on the main `address`, which keeps slow scrapes of high cardinality families from blocking the rest."
+			required:    false
+			warnings: []
+			type: array: {
+				default: []
+				items: type: object: {
+					options: {
+						address: {
+							description: "The address to expose the claimed metrics on."
+							required:    true
+							warnings: []
+							type: string: {
+								examples: ["0.0.0.0:9599"]
+							}
+						}
+						prefixes: {
+							description: "The metric name prefixes claimed by this listener, matched against the name before the namespace is applied."
+							required:    true
+							warnings: []
+							type: array: items: type: string: examples: ["http_"]
+						}
+					}
+				}
+			}
+		}
+		max_body_bytes: {
+			common:      false
+			description: "The maximum size of the exposition of a scrape, for scrapers with a fixed limit on the size of responses. Past it, families of series are dropped whole from the end of the exposition, and a `<namespace>_prometheus_truncated` gauge of `1` is appended in their place. The series of a family are then exposed together, even if they weren't received together. The metrics appended by `emit_cardinality_metrics`, `emit_ack_metrics` and `emit_scrape_errors`, the trailing newline and the `# EOF` trailer are kept, and counted towards the limit."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [10000000]
+				unit:    "bytes"
+			}
+		}
+		max_labels_per_series: {
+			common:      false
+			description: "The maximum number of labels of a series. Series received with more labels are handled as set by `label_limit_action`, with a warning and a processing error. Unlimited when unset."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [10]
+				unit:    null
+			}
+		}
+		max_request_body_bytes: {
+			common:      false
+			description: "The maximum body size of a request. Scrapes have no body, so requests past this limit, whether their size is announced or they are sent chunked, are rejected with `413`. No more than the limit is read of a body. When unset, bodies are ignored regardless of their size."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [0]
+				unit:    "bytes"
+			}
+		}
+		max_request_header_bytes: {
+			common:      false
+			description: "The maximum size of the buffer used to read a request's headers. Requests with larger headers are rejected with `431`. When unset, the HTTP server's default of about 400KB applies."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [16384]
+				unit:    "bytes"
+			}
+		}
+		max_series_per_metric: {
 			common:      false
-			description: "Default buckets to use for aggregating [distribution][docs.data-model.metric#distribution] metrics into histograms."
+			description: "A map from metric name to the maximum number of distinct label sets stored for it, to bound the cardinality of single families. Series received past the limit are dropped with a warning and a processing error, while the stored series keep being updated."
 			required:    false
 			warnings: []
-			type: array: {
-				default: [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]
-				items: type: float: examples: [0.005, 0.01]
+			type: object: {
+				examples: [{"http_requests_total": 1000}]
+				options: {}
 			}
 		}
-		flush_period_secs: {
+		max_tracked_series: {
 			common:      false
-			description: "Time interval between [set][docs.data-model.metric#set] values are reset."
+			description: "The maximum number of series for which auxiliary state (such as the time of the last update) is tracked. When the limit is reached, the state of the least recently updated or scraped series is evicted first. This does not limit the number of series exposed."
 			required:    false
 			warnings: []
 			type: uint: {
-				default: 60
-				unit:    "seconds"
+				default: 100000
+				unit:    null
 			}
 		}
+		metric_names_endpoint: {
+			common:      false
+			description: "Whether to serve `GET /-/metric-names`, a JSON array of the distinct names of the metrics exposed by the listener, sorted, so tooling can enumerate metric families without parsing a full scrape."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
 		namespace: {
 			common:      true
 			description: "A prefix that will be added to all metric names.\nIt should follow Prometheus [naming conventions](\(urls.prometheus_metric_naming))."
@@ -102,6 +694,144 @@ components: sinks: prometheus: {
 				examples: ["service"]
 			}
 		}
+		normalize_label_values: {
+			common:      false
+			description: "Normalization applied to labels as metrics are received, so labels differing only in casing or surrounding whitespace share a series."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: []
+				options: {
+					exempt_labels: {
+						common:      false
+						description: "Labels whose values are left untouched."
+						required:    false
+						warnings: []
+						type: array: {
+							default: []
+							items: type: string: examples: ["path"]
+						}
+					}
+					lowercase: {
+						common:      false
+						description: "Whether to lowercase label values."
+						required:    false
+						warnings: []
+						type: bool: default: false
+					}
+					lowercase_names: {
+						common:      false
+						description: "Whether to lowercase label names, so `Host` and `host` are the same label. When several labels of a series end up with the same name, the first in name order is kept, uppercase sorting first, and the others are dropped with a warning if their value differs."
+						required:    false
+						warnings: []
+						type: bool: default: false
+					}
+					trim: {
+						common:      false
+						description: "Whether to trim leading and trailing whitespace from label values."
+						required:    false
+						warnings: []
+						type: bool: default: false
+					}
+				}
+			}
+		}
+		not_found_body: {
+			common:      false
+			description: "The body of responses to requests for unknown paths, for instance a help message for uptime checkers requesting `/`."
+			required:    false
+			warnings: []
+			type: string: {
+				default: ""
+				examples: ["Metrics are served on /metrics"]
+			}
+		}
+		not_found_status: {
+			common:      false
+			description: "The status code of responses to requests for unknown paths."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: 404
+				examples: [200]
+				unit: null
+			}
+		}
+		original_name_label: {
+			common:      false
+			description: "The label holding the original name of the metrics renamed by `sanitize_names`, when `emit_original_name_label` is set."
+			required:    false
+			warnings: []
+			type: string: {
+				default: "__original_name__"
+				examples: ["original_name"]
+			}
+		}
+		persistence_interval_secs: {
+			common:      false
+			description: "How often the stored metrics are saved to `persistence_path`. They are also saved when the sink shuts down."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: 60
+				unit:    "seconds"
+			}
+		}
+		persistence_path: {
+			common:      false
+			description: "A file the stored metrics are saved to, and restored from when the sink starts, so counters resume from their totals across restarts. Snapshots are written next to the file then renamed over it, so a crash never leaves a partial snapshot. A snapshot that can't be read, or was written by an incompatible version, is ignored."
+			required:    false
+			warnings: ["Saving blocks ingestion for as long as it takes to serialize every stored metric."]
+			type: string: {
+				default: null
+				examples: ["/var/lib/vector/prometheus.json"]
+			}
+		}
+		precompute_interval_secs: {
+			common:      false
+			description: "Render the exposition, and compress it with gzip, in the background at this interval, so scrapes of `/metrics` send the last render instead of rendering their own. Scrapes accepting gzip get the compressed render. Values ingested since the last render show up at the next one. Scrapes selecting metrics with `name[]` are still rendered on demand. Can't be combined with `reset_on_scrape`."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [5]
+				unit: "seconds"
+			}
+		}
+		quantile_gauges: {
+			common:      false
+			description: "Quantiles of every [distribution][docs.data-model.metric#distribution] and aggregated histogram to additionally expose as a plain [gauge][docs.data-model.metric#gauge] named after the metric and the percentile, such as `requests_p99` or `requests_p99_9`, for dashboards and alerts that don't query summaries. Quantiles of aggregated histograms are interpolated within their buckets like `histogram_quantile` does, so they are only as precise as the buckets are narrow."
+			required:    false
+			warnings: []
+			type: array: {
+				default: []
+				items: type: float: examples: [0.99]
+			}
+		}
+		quantile_label_precision: {
+			common:      false
+			description: "The number of decimals used to render summary quantiles in the `quantile` label. By default the shortest representation of the quantile is used. Building fails if several quantiles are rendered the same."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [2]
+				unit: null
+			}
+		}
+		quantile_method: {
+			common:      false
+			description: "The method used to compute `quantiles` when aggregating [distribution][docs.data-model.metric#distribution] metrics into a summary. Samples are sorted first, so tied samples always yield the same value."
+			required:    false
+			warnings: []
+			type: string: {
+				default: "nearest_rank"
+				enum: {
+					nearest_rank: "The sample whose rank is nearest to `quantile * count`, rounding half away from zero."
+					linear:       "Linear interpolation between the two samples closest to the fractional rank `quantile * (count - 1)`."
+				}
+			}
+		}
 		quantiles: {
 			common:      false
 			description: "Quantiles to use for aggregating [distribution][docs.data-model.metric#distribution] metrics into a summary."
@@ -112,6 +842,339 @@ components: sinks: prometheus: {
 				items: type: float: examples: [0.5, 0.75, 0.9, 0.95, 0.99]
 			}
 		}
+		relabel_configs: {
+			common:      false
+			description: "Relabeling rules applied in order to every exposed series, with the semantics of Prometheus's `relabel_configs`. The metric name is available to the rules as the `__name__` label, and labels still starting with `__` once all rules are applied are left out. Relabeling happens before `aggregate_drop_labels` and leaves the stored series untouched."
+			required:    false
+			warnings: ["Relabeling copies every exposed series on each scrape."]
+			type: array: {
+				default: []
+				items: type: object: {
+					options: {
+						action: {
+							common:      false
+							description: "What the rule does."
+							required:    false
+							warnings: []
+							type: string: {
+								default: "replace"
+								enum: {
+									replace:   "Set `target_label` to `replacement` if `regex` matches the source labels. The label is removed if the replacement is empty."
+									keep:      "Drop the series unless `regex` matches the source labels."
+									drop:      "Drop the series if `regex` matches the source labels."
+									labelmap:  "Copy the value of every label whose name matches `regex` to the label named `replacement`."
+									labeldrop: "Remove every label whose name matches `regex`."
+									labelkeep: "Remove every label whose name doesn't match `regex`."
+								}
+							}
+						}
+						regex: {
+							common:      false
+							description: "The regex matched against the joined source labels, or against label names for `labelmap`, `labeldrop` and `labelkeep`. It must match the whole value."
+							required:    false
+							warnings: []
+							type: string: {
+								default: "(.*)"
+								examples: ["(GET|POST);(\\d)\\d\\d"]
+							}
+						}
+						replacement: {
+							common:      false
+							description: "The value set by `replace` and the label name set by `labelmap`, where `$1`, `${name}` and so on refer to the groups captured by `regex`."
+							required:    false
+							warnings: []
+							type: string: {
+								default: "$1"
+								examples: ["${1}_${2}xx"]
+							}
+						}
+						separator: {
+							common:      false
+							description: "The separator the values of the source labels are joined with."
+							required:    false
+							warnings: []
+							type: string: {
+								default: ";"
+								examples: [","]
+							}
+						}
+						source_labels: {
+							common:      false
+							description: "The labels whose values are joined and matched against `regex`. Missing labels contribute an empty value."
+							required:    false
+							warnings: []
+							type: array: {
+								default: []
+								items: type: string: examples: ["method", "status"]
+							}
+						}
+						target_label: {
+							common:      false
+							description: "The label set by `replace`, which requires it."
+							required:    false
+							warnings: []
+							type: string: {
+								default: null
+								examples: ["class"]
+							}
+						}
+					}
+				}
+			}
+		}
+		render_cache_max_age_ms: {
+			common:      false
+			description: "Enables reusing a render of the metrics for scrapes arriving shortly after it. A render is reused for up to half the time between the two previous renders, and at most this long, so scrapers polling in a burst share a render while a scraper polling alone always gets fresh metrics. Scrapes served a reused render don't count toward `expire_after_scrapes`. Renders are never reused with `reset_on_scrape`."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [1000]
+				unit:    "milliseconds"
+			}
+		}
+		reset_on_scrape: {
+			common:      false
+			description: "Whether to clear the samples of [distribution][docs.data-model.metric#distribution] metrics after each scrape of the listener exposing them, so every scrape reflects a fresh interval rather than all samples since startup."
+			required:    false
+			warnings: ["Scrapes hold an exclusive lock on the stored metrics while they render, so ingestion waits for them and `scrape_lock_timeout_ms` does not apply to `/metrics`."]
+			type: bool: default: false
+		}
+		reset_timestamp_label: {
+			common:      false
+			description: "A label added to every counter with the Unix timestamp, in seconds, of when its series was first received or its source last restarted, an alternative to the OpenMetrics `_created` series. A restart is detected when an absolute counter goes below its stored value, so the label only changes on restarts. The timestamp of a series evicted past `max_tracked_series` restarts from its next update."
+			required:    false
+			warnings: []
+			type: string: {
+				default: null
+				examples: ["reset_time"]
+			}
+		}
+		sample_rate: {
+			common:      false
+			description: "A map from metric name to the fraction of its series, between `0` and `1`, admitted to the store, to bound the cardinality of high-cardinality debug metrics. Whether a series is admitted depends on a stable hash of its labels, so the same series are always kept. Metrics not listed are never sampled."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: [{"debug_requests_total": 0.1}]
+				options: {}
+			}
+		}
+		sanitize_names: {
+			common:      false
+			description: "Whether to expose metrics with a name Prometheus would reject, such as `system.load`, under a name where every invalid character is replaced with an underscore, such as `system_load`, instead of leaving them out of scrapes. Names starting with a digit are prefixed with an underscore."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		scrape_lock_timeout_ms: {
+			common:      false
+			description: "How long a scrape waits for the stored metrics while they are being updated. When the wait times out, the last rendered exposition is served, or a `503 Service Unavailable` response with a `Retry-After` header if nothing has been rendered yet. By default scrapes wait until the update completes."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [250]
+				unit: "milliseconds"
+			}
+		}
+		self_up_metric: {
+			common:      false
+			description: "Whether to prepend a `<namespace>_up` gauge with a value of `1` to every scrape, so dashboards can tell a scrape without data apart from a failed scrape. The gauge is generated per scrape and is not stored."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		series_collisions: {
+			common:      false
+			description: "How series that end up with the same name and labels once relabeled, exploded or aggregated are exposed, as scrapers reject duplicate samples."
+			required:    false
+			warnings: []
+			type: string: {
+				default: "keep"
+				enum: {
+					keep:  "Expose all of them, as duplicate samples."
+					sum:   "Expose a single series adding up their values. Summaries can't be added up, so the last one is kept."
+					last:  "Expose the last of them."
+					error: "Expose none of them, counting them as a `series_collision` scrape error."
+				}
+			}
+		}
+		shutdown_push_url: {
+			common:      false
+			description: "An endpoint, such as a Pushgateway group, the whole exposition is sent to with a `POST` request once the input has ended, before the server stops, so the last values received aren't lost with it. A failed push is logged, without delaying the shutdown any further."
+			required:    false
+			warnings: []
+			type: string: {
+				default: null
+				examples: ["http://pushgateway:9091/metrics/job/vector"]
+			}
+		}
+		sort_series: {
+			common:      false
+			description: "Whether to expose the series sorted by name, then by label set, so the samples of a family are contiguous and scrapes are reproducible. Otherwise series are exposed in the order they were stored, which differs across runs."
+			required:    false
+			warnings: ["Sorting adds to the time spent rendering every scrape."]
+			type: bool: default: false
+		}
+		stats_endpoint: {
+			common:      false
+			description: "Whether to serve `GET /-/stats`, a lightweight JSON summary of the metrics exposed by the listener: the number of `families` and `series`, the `oldest_update_timestamp` and `newest_update_timestamp` of those tracked, and the `last_render_duration_seconds` of the listener."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		stream_endpoint: {
+			common:      false
+			description: "Whether to accept WebSocket connections on `GET /metrics/stream`, pushing the exposition of the listener as a text message as soon as a client connects, then every `flush_period_secs`, for live debugging from a browser. Messages don't count as scrapes, so they don't expire series. Pings from clients are answered and a close ends the stream, other messages sent by clients are ignored."
+			required:    false
+			warnings: ["This is a debugging aid, Prometheus can't scrape it."]
+			type: bool: default: false
+		}
+		suppress_empty_histograms: {
+			common:      false
+			description: "Whether to leave histograms without any samples out of the exposition entirely, rather than exposing their buckets, sum and count at zero. Dashboards then show no data for them instead of a flat line."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		sweep_interval_secs: {
+			common:      false
+			description: "The interval between sweeps evicting the series expired by `expire_after_scrapes` in the background, so the memory of gauges whose `hold_last_value_secs` window elapsed is reclaimed without waiting for the next scrape. A sweep is skipped while the stored metrics are being scraped or updated. When unset, series are only evicted after scrapes."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [60]
+				unit: "seconds"
+			}
+		}
+		temporality: {
+			common:      false
+			description: "How the values of absolute [counters][docs.data-model.metric#counter] relate to previous values."
+			required:    false
+			warnings: []
+			type: string: {
+				default: "cumulative"
+				enum: {
+					cumulative: "Each value is the total since the counter started, and replaces the exposed value."
+					delta:      "Each value is the increment since the previous one, as sent by OTLP exporters using delta temporality, and is added to the exposed value."
+				}
+			}
+		}
+		tenant_label: {
+			common:      false
+			description: "A label holding the tenant of a series. When set, the name of each series is prefixed with its tenant, so the metrics of different tenants don't collide, and the label is removed. For example, `requests{tenant=\"acme\"}` is exposed as `acme_requests`. This is applied after `relabel_configs`."
+			required:    false
+			warnings: []
+			type: string: {
+				default: null
+				examples: ["tenant"]
+			}
+		}
+		trailing_newline: {
+			common:      false
+			description: "Whether to end the exposition with a blank line, for parsers that require the body to be terminated by an empty line. Prometheus ignores blank lines. Can't be combined with `emit_eof`, as OpenMetrics forbids blank lines."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		type_overrides: {
+			common:      false
+			description: "A map from metric name to the Prometheus type it is exposed as, one of `counter`, `gauge`, `histogram` or `summary`. A counter or gauge can be exposed as either of those two types, and a distribution as a histogram or a summary. Exposing a distribution of summary statistics as a histogram, bucketed by `buckets`, suits tools that need buckets rather than quantiles, such as Grafana heatmaps; `dual_emit` exposes both forms instead. Overrides incompatible with the metric value are ignored with a warning."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: [{"uptime_seconds": "counter"}]
+				options: {}
+			}
+		}
+		unit_of: {
+			common:      false
+			description: "A map from metric name to its unit, selecting the buckets of its histograms in `bucket_schemas`. Every unit must have a schema."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: [{"request_duration": "seconds", "response_size": "bytes"}]
+				options: {}
+			}
+		}
+		unknown_type_for_sets: {
+			common:      false
+			description: "Whether to expose [sets][docs.data-model.metric#set] with the OpenMetrics `unknown` type, rather than as a gauge of the number of distinct values, in OpenMetrics expositions. The sample is the number of distinct values either way. Parsers of the Prometheus text format don't know the type, so it requires `emit_eof`, which serves expositions as OpenMetrics."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		unnamed_metric_name: {
+			common:      false
+			description: "The name given to metrics received without one. When unset, such metrics are dropped and counted as processing errors."
+			required:    false
+			warnings: []
+			type: string: {
+				default: null
+				examples: ["unnamed"]
+			}
+		}
+		validate_counters: {
+			common:      false
+			description: "Whether to reject [counter][docs.data-model.metric#counter] metrics with a negative value. Rejected events are dropped with a warning, as a negative counter breaks the Prometheus counter contract."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		validate_encoding: {
+			common:      false
+			description: "Whether to encode a sample of every kind of metric when the sink is built, failing the build if the configuration makes encoding fail, such as a `namespace` that isn't a valid metric name prefix. This lets `vector validate` catch these configurations instead of every scrape reporting encoding errors."
+			required:    false
+			warnings: []
+			type: bool: default: false
+		}
+		value_clamp: {
+			common:      false
+			description: "The range the values of counters and gauges, and the sums of histograms and summaries, are clamped to, so a single absurd sample such as a gauge of `1e300` doesn't break downstream systems. Every clamped value is counted by the `values_clamped_total` internal metric. Bucket boundaries and quantiles are left as is."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: []
+				options: {
+					max: {
+						description: "The largest value exposed."
+						required:    true
+						warnings: []
+						type: float: examples: [1e9]
+					}
+					min: {
+						description: "The smallest value exposed. It can't be greater than `max`."
+						required:    true
+						warnings: []
+						type: float: examples: [-1e9]
+					}
+				}
+			}
+		}
+		warmup_min_series: {
+			common:      false
+			description: "The number of series a listener must expose to end its warmup before `warmup_secs` elapse."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [100]
+				unit:    null
+			}
+		}
+		warmup_secs: {
+			common:      false
+			description: "The time after a listener starts during which scrapes are answered with `503 Service Unavailable` and a `Retry-After` header, so scrapers back off instead of recording a baseline of an empty store. The warmup ends early once the listener exposes `warmup_min_series` series, if set."
+			required:    false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [30]
+				unit:    "seconds"
+			}
+		}
 	}
 
 	input: {
@@ -228,9 +1291,60 @@ components: sinks: prometheus: {
 						buckets customized to your use case.
 						"""
 				},
+				{
+					title: "Generated Buckets"
+					body: """
+						Like the helpers of the Prometheus client libraries, `buckets` can be
+						generated instead of listed:
+
+						* `buckets = { type = "linear", start = 0.5, width = 0.5, count = 4 }`
+						  generates `count` buckets, the first one at `start`, each `width` wide.
+						* `buckets = { type = "exponential", start = 0.001, factor = 2, count = 12 }`
+						  generates `count` buckets, the first one at `start`, each `factor`
+						  times the previous one.
+
+						Linear buckets require a positive `width`, exponential buckets a positive
+						`start` and a `factor` greater than one, and both a positive `count`.
+						"""
+				},
 			]
 		}
 
+		conditional_requests: {
+			title: "Conditional Requests"
+			body: """
+				Every scrape carries an `ETag` header computed from the rendered metrics.
+				A scrape whose `If-None-Match` header lists that etag is answered with
+				`304 Not Modified` and no body, letting caching proxies in front of Vector
+				serve frequent scrapers without transferring unchanged metrics.
+				"""
+		}
+
+		targeted_scrapes: {
+			title: "Targeted Scrapes"
+			body: """
+				A scrape can ask for only some metric families by listing their names,
+				namespace included, in `name[]` query parameters, as in
+				`/metrics?name[]=http_requests_total`. Without them, every family is exposed.
+				Targeted scrapes don't count towards `expire_after_scrapes`, don't reset
+				distributions with `reset_on_scrape`, and are never served from the render
+				cache.
+				"""
+		}
+
+		store_state: {
+			title: "Store State"
+			body: """
+				Every scrape carries an `X-Vector-Store-State` header telling a broken
+				exporter apart from one without data yet. It is `ok` when metrics are
+				stored, and `empty` when none have been received, in which case the scrape
+				succeeds with an empty body. It is `degraded` when the stored metrics can't
+				be read, either because an update panicked while holding them, in which case
+				the scrape fails with `503 Service Unavailable`, or because the wait set by
+				`scrape_lock_timeout_ms` timed out.
+				"""
+		}
+
 		memory_usage: {
 			title: "Memory Usage"
 			body: """
@@ -54,6 +54,8 @@ mod mongodb_metrics;
 mod process;
 #[cfg(feature = "sources-prometheus")]
 mod prometheus;
+#[cfg(feature = "sinks-prometheus")]
+mod prometheus_sink;
 #[cfg(feature = "transforms-reduce")]
 mod reduce;
 #[cfg(feature = "transforms-regex_parser")]
@@ -152,6 +154,8 @@ pub(crate) use self::metric_to_log::*;
 pub use self::process::*;
 #[cfg(feature = "sources-prometheus")]
 pub use self::prometheus::*;
+#[cfg(feature = "sinks-prometheus")]
+pub use self::prometheus_sink::*;
 #[cfg(feature = "transforms-reduce")]
 pub(crate) use self::reduce::*;
 #[cfg(feature = "transforms-regex_parser")]
@@ -0,0 +1,370 @@
+use super::InternalEvent;
+use crate::{
+    event::metric::StatisticKind,
+    sinks::prometheus::{EncodeError, LabelLimitAction},
+};
+use chrono::{DateTime, Utc};
+use http::StatusCode;
+use metrics::{counter, gauge, histogram};
+use std::{
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug)]
+pub struct PrometheusServerStarted {
+    pub address: SocketAddr,
+}
+
+impl InternalEvent for PrometheusServerStarted {
+    fn emit_logs(&self) {
+        info!(message = "Serving metrics.", address = %self.address);
+    }
+
+    fn emit_metrics(&self) {
+        counter!("listeners_started_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusServerFailed<'a> {
+    pub address: SocketAddr,
+    pub error: &'a hyper::Error,
+}
+
+impl<'a> InternalEvent for PrometheusServerFailed<'a> {
+    fn emit_logs(&self) {
+        error!(message = "Server failed.", address = %self.address, error = %self.error);
+    }
+
+    fn emit_metrics(&self) {
+        counter!("server_errors_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusServerBindFailed<'a> {
+    pub address: SocketAddr,
+    pub error: &'a std::io::Error,
+    pub retries_left: u32,
+}
+
+impl<'a> InternalEvent for PrometheusServerBindFailed<'a> {
+    fn emit_logs(&self) {
+        if self.retries_left > 0 {
+            warn!(
+                message = "Failed to bind, retrying.",
+                address = %self.address,
+                error = %self.error,
+                retries_left = %self.retries_left,
+            );
+        } else {
+            error!(
+                message = "Failed to bind.",
+                address = %self.address,
+                error = %self.error,
+            );
+        }
+    }
+
+    fn emit_metrics(&self) {
+        counter!("bind_errors_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusScrapeRendered {
+    pub series: usize,
+    pub byte_size: usize,
+    pub duration: Duration,
+}
+
+impl InternalEvent for PrometheusScrapeRendered {
+    fn emit_logs(&self) {
+        debug!(
+            message = "Rendered metrics.",
+            series = %self.series,
+            byte_size = %self.byte_size,
+        );
+    }
+
+    fn emit_metrics(&self) {
+        counter!("scrapes_rendered_total", 1);
+        gauge!("exposed_series", self.series as f64);
+        gauge!(
+            "last_scrape_timestamp_seconds",
+            Utc::now().timestamp() as f64
+        );
+        histogram!("render_duration_nanoseconds", self.duration);
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusScrapeTruncated {
+    pub max_bytes: usize,
+    pub dropped_families: usize,
+}
+
+impl InternalEvent for PrometheusScrapeTruncated {
+    fn emit_logs(&self) {
+        warn!(
+            message = "Scrape exceeds the maximum body size; truncating it.",
+            max_bytes = %self.max_bytes,
+            dropped_families = %self.dropped_families,
+            rate_limit_secs = 30,
+        );
+    }
+
+    fn emit_metrics(&self) {
+        counter!("scrapes_truncated_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusShutdownPushed<'a> {
+    pub url: &'a str,
+    pub bytes: usize,
+}
+
+impl<'a> InternalEvent for PrometheusShutdownPushed<'a> {
+    fn emit_logs(&self) {
+        info!(message = "Pushed final state.", url = %self.url, bytes = %self.bytes);
+    }
+
+    fn emit_metrics(&self) {
+        counter!("shutdown_pushes_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusShutdownPushFailed<'a> {
+    pub url: &'a str,
+    pub error: &'a str,
+}
+
+impl<'a> InternalEvent for PrometheusShutdownPushFailed<'a> {
+    fn emit_logs(&self) {
+        error!(
+            message = "Failed to push final state.",
+            url = %self.url,
+            error = %self.error,
+        );
+    }
+
+    fn emit_metrics(&self) {
+        counter!("shutdown_push_errors_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusScrapeCompleted {
+    pub status: StatusCode,
+    pub start: Instant,
+    pub end: Instant,
+}
+
+impl InternalEvent for PrometheusScrapeCompleted {
+    fn emit_logs(&self) {
+        debug!(message = "Request completed.", response_code = ?self.status);
+    }
+
+    fn emit_metrics(&self) {
+        counter!("requests_completed_total", 1);
+        histogram!("request_duration_nanoseconds", self.end - self.start);
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusNegativeCounterRejected<'a> {
+    pub name: &'a str,
+    pub value: f64,
+}
+
+impl<'a> InternalEvent for PrometheusNegativeCounterRejected<'a> {
+    fn emit_logs(&self) {
+        warn!(
+            message = "Negative counter value received; dropping event.",
+            name = %self.name,
+            value = %self.value,
+            rate_limit_secs = 30,
+        );
+    }
+
+    fn emit_metrics(&self) {
+        counter!("processing_errors_total", 1, "error_type" => "negative_counter");
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusOutOfOrderSampleDropped<'a> {
+    pub name: &'a str,
+    pub timestamp: DateTime<Utc>,
+    pub newest: DateTime<Utc>,
+}
+
+impl<'a> InternalEvent for PrometheusOutOfOrderSampleDropped<'a> {
+    fn emit_logs(&self) {
+        warn!(
+            message = "Sample older than the newest one of its series received; dropping event.",
+            name = %self.name,
+            timestamp = %self.timestamp,
+            newest = %self.newest,
+            rate_limit_secs = 30,
+        );
+    }
+
+    fn emit_metrics(&self) {
+        counter!("processing_errors_total", 1, "error_type" => "out_of_order");
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusSeriesCollided<'a> {
+    pub name: &'a str,
+    pub series: usize,
+}
+
+impl<'a> InternalEvent for PrometheusSeriesCollided<'a> {
+    fn emit_logs(&self) {
+        warn!(
+            message = "Series share their name and labels once transformed; leaving them out of the scrape.",
+            name = %self.name,
+            series = %self.series,
+            rate_limit_secs = 30,
+        );
+    }
+
+    fn emit_metrics(&self) {
+        counter!("processing_errors_total", 1, "error_type" => "series_collision");
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusUnnamedMetricRejected;
+
+impl InternalEvent for PrometheusUnnamedMetricRejected {
+    fn emit_logs(&self) {
+        warn!(
+            message = "Metric without a name received; dropping event.",
+            rate_limit_secs = 30,
+        );
+    }
+
+    fn emit_metrics(&self) {
+        counter!("processing_errors_total", 1, "error_type" => "unnamed_metric");
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusLabelLimitExceeded<'a> {
+    pub name: &'a str,
+    pub labels: usize,
+    pub max_labels: usize,
+    pub action: LabelLimitAction,
+}
+
+impl<'a> InternalEvent for PrometheusLabelLimitExceeded<'a> {
+    fn emit_logs(&self) {
+        let message = match self.action {
+            LabelLimitAction::Drop => "Metric has too many labels; dropping event.",
+            LabelLimitAction::Truncate => "Metric has too many labels; truncating labels.",
+        };
+        warn!(
+            message,
+            name = %self.name,
+            labels = %self.labels,
+            max_labels = %self.max_labels,
+            rate_limit_secs = 30,
+        );
+    }
+
+    fn emit_metrics(&self) {
+        counter!("processing_errors_total", 1, "error_type" => "label_limit_exceeded");
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusSeriesLimitExceeded<'a> {
+    pub name: &'a str,
+    pub max_series: usize,
+}
+
+impl<'a> InternalEvent for PrometheusSeriesLimitExceeded<'a> {
+    fn emit_logs(&self) {
+        warn!(
+            message = "Metric has too many series; dropping new series.",
+            name = %self.name,
+            max_series = %self.max_series,
+            rate_limit_secs = 30,
+        );
+    }
+
+    fn emit_metrics(&self) {
+        counter!("processing_errors_total", 1, "error_type" => "series_limit_exceeded");
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusValueClamped<'a> {
+    pub name: &'a str,
+    pub value: f64,
+}
+
+impl<'a> InternalEvent for PrometheusValueClamped<'a> {
+    fn emit_logs(&self) {
+        warn!(
+            message = "Sample value out of range; clamping it.",
+            name = %self.name,
+            value = %self.value,
+            rate_limit_secs = 30,
+        );
+    }
+
+    fn emit_metrics(&self) {
+        counter!("values_clamped_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusStatisticMismatchRejected<'a> {
+    pub name: &'a str,
+    pub stored: StatisticKind,
+    pub received: StatisticKind,
+}
+
+impl<'a> InternalEvent for PrometheusStatisticMismatchRejected<'a> {
+    fn emit_logs(&self) {
+        warn!(
+            message = "Distribution statistic differs from the stored one; dropping event.",
+            name = %self.name,
+            stored = ?self.stored,
+            received = ?self.received,
+            rate_limit_secs = 30,
+        );
+    }
+
+    fn emit_metrics(&self) {
+        counter!("processing_errors_total", 1, "error_type" => "statistic_mismatch");
+    }
+}
+
+#[derive(Debug)]
+pub struct PrometheusEncodeFailed<'a> {
+    pub name: &'a str,
+    pub error: &'a EncodeError,
+}
+
+impl<'a> InternalEvent for PrometheusEncodeFailed<'a> {
+    fn emit_logs(&self) {
+        warn!(
+            message = "Failed to encode metric; leaving it out of the scrape.",
+            name = %self.name,
+            error = %self.error,
+            rate_limit_secs = 30,
+        );
+    }
+
+    fn emit_metrics(&self) {
+        counter!("encode_errors_total", 1, "error_type" => self.error.kind());
+    }
+}
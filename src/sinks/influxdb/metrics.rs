@@ -206,7 +206,7 @@ fn merge_tags(
     }
 }
 
-fn encode_events(
+pub(in crate::sinks) fn encode_events(
     protocol_version: ProtocolVersion,
     events: Vec<Metric>,
     namespace: Option<&str>,
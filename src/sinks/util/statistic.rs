@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use snafu::Snafu;
 use std::cmp::Ordering;
 
@@ -7,6 +8,40 @@ pub enum ValidationError {
     QuantileOutOfRange,
 }
 
+/// The method used to pick a quantile value out of the sorted samples.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuantileMethod {
+    /// The sample whose rank is nearest to `q * count`, rounding half away
+    /// from zero and clamping to the first and last sample.
+    NearestRank,
+    /// Linear interpolation between the two samples closest to the fractional
+    /// rank `q * (count - 1)`.
+    Linear,
+}
+
+impl Default for QuantileMethod {
+    fn default() -> Self {
+        QuantileMethod::NearestRank
+    }
+}
+
+impl QuantileMethod {
+    /// Computes the quantile `q` of the non-empty, sorted `samples`.
+    fn quantile(self, samples: &[f64], q: f64) -> f64 {
+        let length = samples.len() as f64;
+        match self {
+            QuantileMethod::NearestRank => samples[(q * length - 1.0).round() as usize],
+            QuantileMethod::Linear => {
+                let rank = q * (length - 1.0);
+                let lower = rank.floor() as usize;
+                let upper = rank.ceil() as usize;
+                samples[lower] + (rank - lower as f64) * (samples[upper] - samples[lower])
+            }
+        }
+    }
+}
+
 pub struct DistributionStatistic {
     pub min: f64,
     pub max: f64,
@@ -20,6 +55,15 @@ pub struct DistributionStatistic {
 
 impl DistributionStatistic {
     pub fn new(values: &[f64], counts: &[u32], quantiles: &[f64]) -> Option<Self> {
+        Self::with_method(values, counts, quantiles, QuantileMethod::default())
+    }
+
+    pub fn with_method(
+        values: &[f64],
+        counts: &[u32],
+        quantiles: &[f64],
+        method: QuantileMethod,
+    ) -> Option<Self> {
         if values.len() != counts.len() {
             return None;
         }
@@ -54,13 +98,10 @@ impl DistributionStatistic {
         let min = *samples.first().unwrap();
         let max = *samples.last().unwrap();
 
-        let median = samples[(0.50 * length - 1.0).round() as usize];
+        let median = method.quantile(&samples, 0.50);
         let quantiles = quantiles
             .iter()
-            .map(|&p| {
-                let sample = samples[(p * length - 1.0).round() as usize];
-                (p, sample)
-            })
+            .map(|&p| (p, method.quantile(&samples, p)))
             .collect();
 
         let sum = samples.iter().sum();
@@ -88,3 +129,39 @@ pub fn validate_quantiles(quantiles: &[f64]) -> Result<(), ValidationError> {
         Err(ValidationError::QuantileOutOfRange)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p90(method: QuantileMethod) -> f64 {
+        let statistic = DistributionStatistic::with_method(
+            &[4.0, 2.0, 3.0, 1.0],
+            &[1, 1, 1, 1],
+            &[0.9],
+            method,
+        )
+        .unwrap();
+        statistic.quantiles[0].1
+    }
+
+    #[test]
+    fn nearest_rank_quantile() {
+        assert_eq!(p90(QuantileMethod::NearestRank), 4.0);
+    }
+
+    #[test]
+    fn linear_quantile() {
+        assert!((p90(QuantileMethod::Linear) - 3.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn quantiles_of_tied_samples() {
+        for &method in &[QuantileMethod::NearestRank, QuantileMethod::Linear] {
+            let statistic =
+                DistributionStatistic::with_method(&[1.0, 2.0], &[1, 3], &[0.5, 0.9], method)
+                    .unwrap();
+            assert_eq!(statistic.quantiles, vec![(0.5, 2.0), (0.9, 2.0)]);
+        }
+    }
+}
@@ -239,9 +239,10 @@ mod test {
             PrometheusSinkConfig {
                 address: out_addr,
                 namespace: Some("vector".into()),
-                buckets: vec![1.0, 2.0, 4.0],
+                buckets: vec![1.0, 2.0, 4.0].into(),
                 quantiles: vec![],
                 flush_period_secs: 1,
+                ..Default::default()
             },
         );
 